@@ -0,0 +1,52 @@
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The EIP-2930 access list.
+
+use alloy_primitives::{B160, B256};
+
+/// A single entry of an [AccessList].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessListItem {
+    pub address: B160,
+    pub storage_keys: Vec<B256>,
+}
+
+/// An EIP-2930 access list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessList(pub Vec<AccessListItem>);
+
+impl AccessList {
+    /// RLP-encodes the access list. An empty list encodes as the explicit empty RLP
+    /// list (`0xc0`) rather than being omitted, as required for canonical
+    /// EIP-2930/EIP-1559 transaction encoding.
+    pub(crate) fn rlp_encode(&self) -> Vec<u8> {
+        let items: Vec<Vec<u8>> = self
+            .0
+            .iter()
+            .map(|item| {
+                let keys: Vec<Vec<u8>> = item
+                    .storage_keys
+                    .iter()
+                    .map(|key| crate::rlp::encode_bytes(key.as_slice()))
+                    .collect();
+                crate::rlp::encode_list(&[
+                    crate::rlp::encode_bytes(item.address.as_slice()),
+                    crate::rlp::encode_list(&keys),
+                ])
+            })
+            .collect();
+        crate::rlp::encode_list(&items)
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The block header.
+
+use alloy_primitives::{Bloom, Bytes, B160, B256, B64, U256};
+
+/// Represents a block header, as specified in
+/// [the Ethereum Yellow Paper](https://ethereum.github.io/yellowpaper/paper.pdf).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Header {
+    pub parent_hash: B256,
+    pub ommers_hash: B256,
+    pub beneficiary: B160,
+    pub state_root: B256,
+    pub transactions_root: B256,
+    pub receipts_root: B256,
+    pub logs_bloom: Bloom,
+    pub difficulty: U256,
+    pub number: u64,
+    pub gas_limit: U256,
+    pub gas_used: U256,
+    pub timestamp: U256,
+    pub extra_data: Bytes,
+    pub mix_hash: B256,
+    pub nonce: B64,
+    pub base_fee_per_gas: U256,
+    pub withdrawals_root: Option<B256>,
+    /// Total gas consumed by the blobs in this block, introduced by EIP-4844.
+    pub blob_gas_used: Option<U256>,
+    /// Running total of blob gas consumed in excess of the target, introduced by
+    /// EIP-4844.
+    pub excess_blob_gas: Option<U256>,
+}
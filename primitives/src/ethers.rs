@@ -30,7 +30,7 @@ use crate::{
     signature::TxSignature,
     transaction::{
         Transaction, TransactionKind, TxEssence, TxEssenceEip1559, TxEssenceEip2930,
-        TxEssenceLegacy,
+        TxEssenceEip4844, TxEssenceLegacy,
     },
     withdrawal::Withdrawal,
 };
@@ -104,6 +104,8 @@ impl<T> TryFrom<EthersBlock<T>> for Header {
                 block.base_fee_per_gas.context("base_fee_per_gas missing")?,
             ),
             withdrawals_root: block.withdrawals_root.map(from_ethers_h256),
+            blob_gas_used: block.blob_gas_used.map(from_ethers_u256),
+            excess_blob_gas: block.excess_blob_gas.map(from_ethers_u256),
         })
     }
 }
@@ -172,7 +174,51 @@ impl TryFrom<EthersTransaction> for Transaction {
                 access_list: tx.access_list.context("access_list missing")?.into(),
                 data: tx.input.0.into(),
             }),
-            _ => unreachable!(),
+            Some(3) => {
+                // `ethers-core` predates Cancun/EIP-4844 support and has no
+                // `max_fee_per_blob_gas`/`blob_versioned_hashes` fields on
+                // [EthersTransaction]; pull them out of the catch-all `other` map
+                // that `#[serde(flatten)]`-captures unrecognized JSON-RPC fields.
+                let max_fee_per_blob_gas: EthersU256 = tx
+                    .other
+                    .get_deserialized("maxFeePerBlobGas")
+                    .context("max_fee_per_blob_gas missing")?
+                    .map_err(|err| anyhow!("invalid max_fee_per_blob_gas: {}", err))?;
+                let blob_versioned_hashes: Vec<EthersH256> = tx
+                    .other
+                    .get_deserialized("blobVersionedHashes")
+                    .context("blob_versioned_hashes missing")?
+                    .map_err(|err| anyhow!("invalid blob_versioned_hashes: {}", err))?;
+                TxEssence::Eip4844(TxEssenceEip4844 {
+                    chain_id: tx
+                        .chain_id
+                        .context("chain_id missing")?
+                        .try_into()
+                        .map_err(|err| anyhow!("invalid chain_id: {}", err))?,
+                    nonce: tx
+                        .nonce
+                        .try_into()
+                        .map_err(|err| anyhow!("invalid nonce: {}", err))?,
+                    max_priority_fee_per_gas: from_ethers_u256(
+                        tx.max_priority_fee_per_gas
+                            .context("max_priority_fee_per_gas missing")?,
+                    ),
+                    max_fee_per_gas: from_ethers_u256(
+                        tx.max_fee_per_gas.context("max_fee_per_gas missing")?,
+                    ),
+                    gas_limit: from_ethers_u256(tx.gas),
+                    to: from_ethers_h160(tx.to.context("to missing")?),
+                    value: from_ethers_u256(tx.value),
+                    access_list: tx.access_list.context("access_list missing")?.into(),
+                    data: tx.input.0.into(),
+                    max_fee_per_blob_gas: from_ethers_u256(max_fee_per_blob_gas),
+                    blob_versioned_hashes: blob_versioned_hashes
+                        .into_iter()
+                        .map(from_ethers_h256)
+                        .collect(),
+                })
+            }
+            Some(n) => return Err(anyhow!("unsupported transaction type: {n}")),
         };
         let signature = TxSignature {
             v: tx.v.as_u64(),
@@ -180,7 +226,7 @@ impl TryFrom<EthersTransaction> for Transaction {
             s: from_ethers_u256(tx.s),
         };
 
-        Ok(Transaction { essence, signature })
+        Ok(Transaction::new(essence, signature))
     }
 }
 
@@ -0,0 +1,211 @@
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Convert from alloy types.
+//!
+//! This mirrors [crate::ethers] field-for-field, but sources its block and
+//! transaction data from `alloy-rpc-types`/`alloy-consensus` instead of
+//! `ethers-core`. It exists so that callers who fetch blocks through an alloy
+//! provider can feed them into the builder directly, without a lossy round-trip
+//! through ethers' types. Both modules must keep producing byte-identical
+//! [Header]/[Transaction] values.
+
+use alloy_primitives::{Address, Bloom, B256, U256};
+use alloy_rpc_types::{
+    AccessList as AlloyAccessList, AccessListItem as AlloyAccessListItem, Block as AlloyBlock,
+    Signature as AlloySignature, Transaction as AlloyTransaction, Withdrawal as AlloyWithdrawal,
+};
+use anyhow::{anyhow, Context};
+
+use crate::{
+    access_list::{AccessList, AccessListItem},
+    block::Header,
+    signature::TxSignature,
+    transaction::{
+        Transaction, TransactionKind, TxEssence, TxEssenceEip1559, TxEssenceEip2930,
+        TxEssenceEip4844, TxEssenceLegacy,
+    },
+    withdrawal::Withdrawal,
+};
+
+#[inline]
+pub fn from_alloy_u256(v: U256) -> U256 {
+    v
+}
+
+#[inline]
+pub fn from_alloy_u128(v: u128) -> U256 {
+    U256::from(v)
+}
+
+#[inline]
+pub fn from_alloy_address(v: Address) -> alloy_primitives::B160 {
+    alloy_primitives::B160::from(v.0)
+}
+
+#[inline]
+pub fn from_alloy_b256(v: B256) -> B256 {
+    v
+}
+
+impl From<AlloyAccessListItem> for AccessListItem {
+    fn from(item: AlloyAccessListItem) -> Self {
+        AccessListItem {
+            address: from_alloy_address(item.address),
+            storage_keys: item
+                .storage_keys
+                .into_iter()
+                .map(from_alloy_b256)
+                .collect(),
+        }
+    }
+}
+
+impl From<AlloyAccessList> for AccessList {
+    fn from(list: AlloyAccessList) -> Self {
+        AccessList(list.0.into_iter().map(|item| item.into()).collect())
+    }
+}
+
+impl From<Option<Address>> for TransactionKind {
+    fn from(addr: Option<Address>) -> Self {
+        match addr {
+            Some(address) => TransactionKind::Call(from_alloy_address(address)),
+            None => TransactionKind::Create,
+        }
+    }
+}
+
+impl<T> TryFrom<AlloyBlock<T>> for Header {
+    type Error = anyhow::Error;
+
+    fn try_from(block: AlloyBlock<T>) -> Result<Self, Self::Error> {
+        let header = block.header;
+        Ok(Header {
+            parent_hash: from_alloy_b256(header.parent_hash),
+            ommers_hash: from_alloy_b256(header.uncles_hash),
+            beneficiary: from_alloy_address(header.miner),
+            state_root: from_alloy_b256(header.state_root),
+            transactions_root: from_alloy_b256(header.transactions_root),
+            receipts_root: from_alloy_b256(header.receipts_root),
+            logs_bloom: Bloom::from_slice(header.logs_bloom.as_slice()),
+            difficulty: from_alloy_u256(header.difficulty),
+            number: header.number.context("number missing")?,
+            gas_limit: from_alloy_u128(header.gas_limit),
+            gas_used: from_alloy_u128(header.gas_used),
+            timestamp: U256::from(header.timestamp),
+            extra_data: header.extra_data,
+            mix_hash: from_alloy_b256(header.mix_hash.context("mix_hash missing")?),
+            nonce: header.nonce.context("nonce missing")?.into(),
+            base_fee_per_gas: from_alloy_u128(
+                header.base_fee_per_gas.context("base_fee_per_gas missing")?,
+            ),
+            withdrawals_root: header.withdrawals_root.map(from_alloy_b256),
+            blob_gas_used: header.blob_gas_used.map(from_alloy_u128),
+            excess_blob_gas: header.excess_blob_gas.map(from_alloy_u128),
+        })
+    }
+}
+
+impl TryFrom<AlloyTransaction> for Transaction {
+    type Error = anyhow::Error;
+
+    fn try_from(tx: AlloyTransaction) -> Result<Self, Self::Error> {
+        let essence = match tx.transaction_type.map(|t| t as u64) {
+            None | Some(0) => TxEssence::Legacy(TxEssenceLegacy {
+                chain_id: tx.chain_id,
+                nonce: tx.nonce,
+                gas_price: from_alloy_u128(tx.gas_price.context("gas_price missing")?),
+                gas_limit: from_alloy_u128(tx.gas),
+                to: tx.to.into(),
+                value: from_alloy_u256(tx.value),
+                data: tx.input,
+            }),
+            Some(1) => TxEssence::Eip2930(TxEssenceEip2930 {
+                chain_id: tx.chain_id.context("chain_id missing")?,
+                nonce: tx.nonce,
+                gas_price: from_alloy_u128(tx.gas_price.context("gas_price missing")?),
+                gas_limit: from_alloy_u128(tx.gas),
+                to: tx.to.into(),
+                value: from_alloy_u256(tx.value),
+                access_list: tx.access_list.context("access_list missing")?.into(),
+                data: tx.input,
+            }),
+            Some(2) => TxEssence::Eip1559(TxEssenceEip1559 {
+                chain_id: tx.chain_id.context("chain_id missing")?,
+                nonce: tx.nonce,
+                max_priority_fee_per_gas: from_alloy_u128(
+                    tx.max_priority_fee_per_gas
+                        .context("max_priority_fee_per_gas missing")?,
+                ),
+                max_fee_per_gas: from_alloy_u128(
+                    tx.max_fee_per_gas.context("max_fee_per_gas missing")?,
+                ),
+                gas_limit: from_alloy_u128(tx.gas),
+                to: tx.to.into(),
+                value: from_alloy_u256(tx.value),
+                access_list: tx.access_list.context("access_list missing")?.into(),
+                data: tx.input,
+            }),
+            Some(3) => TxEssence::Eip4844(TxEssenceEip4844 {
+                chain_id: tx.chain_id.context("chain_id missing")?,
+                nonce: tx.nonce,
+                max_priority_fee_per_gas: from_alloy_u128(
+                    tx.max_priority_fee_per_gas
+                        .context("max_priority_fee_per_gas missing")?,
+                ),
+                max_fee_per_gas: from_alloy_u128(
+                    tx.max_fee_per_gas.context("max_fee_per_gas missing")?,
+                ),
+                gas_limit: from_alloy_u128(tx.gas),
+                to: from_alloy_address(tx.to.context("to missing")?),
+                value: from_alloy_u256(tx.value),
+                access_list: tx.access_list.context("access_list missing")?.into(),
+                data: tx.input,
+                max_fee_per_blob_gas: from_alloy_u128(
+                    tx.max_fee_per_blob_gas
+                        .context("max_fee_per_blob_gas missing")?,
+                ),
+                blob_versioned_hashes: tx
+                    .blob_versioned_hashes
+                    .context("blob_versioned_hashes missing")?
+                    .into_iter()
+                    .map(from_alloy_b256)
+                    .collect(),
+            }),
+            Some(n) => return Err(anyhow!("unsupported transaction type: {n}")),
+        };
+        let AlloySignature { v, r, s, .. } = tx.signature.context("signature missing")?;
+        let signature = TxSignature {
+            v: v.try_into().map_err(|err| anyhow!("invalid v: {}", err))?,
+            r: from_alloy_u256(r),
+            s: from_alloy_u256(s),
+        };
+
+        Ok(Transaction::new(essence, signature))
+    }
+}
+
+impl TryFrom<AlloyWithdrawal> for Withdrawal {
+    type Error = anyhow::Error;
+
+    fn try_from(withdrawal: AlloyWithdrawal) -> Result<Self, Self::Error> {
+        Ok(Withdrawal {
+            index: withdrawal.index,
+            validator_index: withdrawal.validator_index,
+            address: from_alloy_address(withdrawal.address),
+            amount: withdrawal.amount,
+        })
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal RLP (Recursive Length Prefix) encoding helpers, shared by the
+//! [crate::transaction] and [crate::access_list] modules.
+//!
+//! This only implements encoding, not decoding, since the primitive types are
+//! always constructed from an already-decoded source (ethers/alloy) and only need
+//! to be re-encoded canonically to compute signing/transaction hashes.
+
+use alloy_primitives::U256;
+
+/// Strips leading zero bytes, as required for RLP's minimal big-endian encoding.
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    &bytes[first_nonzero..]
+}
+
+fn encode_length(len: usize, offset: u8, out: &mut Vec<u8>) {
+    if len < 56 {
+        out.push(offset + len as u8);
+    } else {
+        let len_be = len.to_be_bytes();
+        let len_bytes = trim_leading_zeros(&len_be);
+        out.push(offset + 55 + len_bytes.len() as u8);
+        out.extend_from_slice(len_bytes);
+    }
+}
+
+/// RLP-encodes a byte string.
+pub(crate) fn encode_bytes(data: &[u8]) -> Vec<u8> {
+    if data.len() == 1 && data[0] < 0x80 {
+        return vec![data[0]];
+    }
+    let mut out = Vec::with_capacity(data.len() + 9);
+    encode_length(data.len(), 0x80, &mut out);
+    out.extend_from_slice(data);
+    out
+}
+
+/// RLP-encodes a list from its already-encoded items.
+pub(crate) fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload_len: usize = items.iter().map(Vec::len).sum();
+    let mut out = Vec::with_capacity(payload_len + 9);
+    encode_length(payload_len, 0xc0, &mut out);
+    for item in items {
+        out.extend_from_slice(item);
+    }
+    out
+}
+
+/// RLP-encodes a `u64` as a minimal big-endian byte string (`0` encodes as the empty
+/// string).
+pub(crate) fn encode_u64(v: u64) -> Vec<u8> {
+    encode_bytes(trim_leading_zeros(&v.to_be_bytes()))
+}
+
+/// RLP-encodes a `U256` as a minimal big-endian byte string (`0` encodes as the empty
+/// string).
+pub(crate) fn encode_u256(v: U256) -> Vec<u8> {
+    encode_bytes(trim_leading_zeros(&v.to_be_bytes::<32>()))
+}
@@ -0,0 +1,85 @@
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ECDSA signature attached to a [Transaction](crate::transaction::Transaction).
+
+use alloy_primitives::U256;
+
+use crate::transaction::TxEssence;
+
+/// The ECDSA `(v, r, s)` triple of a signed transaction.
+///
+/// `v` is stored exactly as it was received from the RLP/JSON encoding: an EIP-155
+/// legacy value (`35 + 2*chain_id + parity`), a pre-EIP-155 value (`27`/`28`), or a
+/// typed-transaction y-parity (`0`/`1`), depending on the [TxEssence] it belongs to.
+/// Use [TxSignature::y_parity] to normalize it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxSignature {
+    pub v: u64,
+    pub r: U256,
+    pub s: U256,
+}
+
+impl TxSignature {
+    /// Normalizes `v` to a single y-parity bit (`0` or `1`), regardless of whether
+    /// it is encoded as a pre-EIP-155 legacy value (`27`/`28`), an EIP-155 legacy
+    /// value (`35 + 2*chain_id + parity`), or an already-0/1 typed-transaction
+    /// y-parity. `essence` must be the essence this signature belongs to, since the
+    /// encoding of `v` depends on it.
+    ///
+    /// This does not itself validate that `v` is well-formed for `essence` - see
+    /// [Transaction::recover_from](crate::transaction::Transaction::recover_from),
+    /// which round-trips this value to reject malformed signatures.
+    pub fn y_parity(&self, essence: &TxEssence) -> u8 {
+        (self.v.wrapping_sub(essence.v_base()) & 1) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::{TxEssenceEip1559, TxEssenceLegacy};
+
+    fn sig(v: u64) -> TxSignature {
+        TxSignature {
+            v,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn y_parity_normalizes_pre_eip155_legacy() {
+        let essence = TxEssence::Legacy(TxEssenceLegacy::default());
+        assert_eq!(sig(27).y_parity(&essence), 0);
+        assert_eq!(sig(28).y_parity(&essence), 1);
+    }
+
+    #[test]
+    fn y_parity_normalizes_eip155_legacy() {
+        let essence = TxEssence::Legacy(TxEssenceLegacy {
+            chain_id: Some(1),
+            ..Default::default()
+        });
+        // v = 35 + 2*1 + parity
+        assert_eq!(sig(37).y_parity(&essence), 0);
+        assert_eq!(sig(38).y_parity(&essence), 1);
+    }
+
+    #[test]
+    fn y_parity_passes_through_typed_transaction_parity() {
+        let essence = TxEssence::Eip1559(TxEssenceEip1559::default());
+        assert_eq!(sig(0).y_parity(&essence), 0);
+        assert_eq!(sig(1).y_parity(&essence), 1);
+    }
+}